@@ -0,0 +1,131 @@
+// Copyright 2020 Red Hat, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CPUID function `0x12` — Intel SGX Capability Enumeration.
+
+use super::Data;
+use std::fmt;
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::__cpuid_count;
+
+/// One SGX EPC section, reported by CPUID function `0x12`, sub-leaves
+/// starting at 2.
+#[derive(Copy, Clone, Debug)]
+pub struct EpcSection {
+    /// The physical base address of the section.
+    pub base: u64,
+    /// The size, in bytes, of the section.
+    pub size: u64,
+}
+
+impl fmt::Display for EpcSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "base: 0x{:016x}, size: 0x{:x}",
+            self.base, self.size
+        )
+    }
+}
+
+/// An iterator over the EPC sections reported by CPUID function `0x12`.
+///
+/// Each sub-leaf, starting at 2, reports a section type in the low
+/// nibble of `EAX` (`0x0` marks the end of the list, `0x1` is a valid
+/// EPC section) along with a base address and size assembled from
+/// `EAX`/`EBX` and `ECX`/`EDX` respectively.
+pub struct EpcSections {
+    sub_leaf: u32,
+    done: bool,
+}
+
+impl EpcSections {
+    fn new() -> Self {
+        Self {
+            sub_leaf: 2,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for EpcSections {
+    type Item = EpcSection;
+
+    #[cfg(target_arch = "x86_64")]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = unsafe { __cpuid_count(0x12, self.sub_leaf) };
+
+        // The low nibble of EAX is the sub-leaf's section type: 0x1 is a
+        // valid EPC section, anything else (notably 0x0) ends the list.
+        if result.eax & 0xf != 0x1 {
+            self.done = true;
+            return None;
+        }
+
+        let base = u64::from(result.eax & 0xffff_f000) | (u64::from(result.ebx & 0x000f_ffff) << 32);
+        let size = u64::from(result.ecx & 0xffff_f000) | (u64::from(result.edx & 0x000f_ffff) << 32);
+
+        self.sub_leaf += 1;
+        Some(EpcSection { base, size })
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    fn next(&mut self) -> Option<Self::Item> {
+        None
+    }
+}
+
+/// Queries the SGX EPC section layout via CPUID function `0x12`.
+pub struct Epc;
+
+impl fmt::Display for Epc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.data() {
+            Some(sections) => {
+                for section in sections {
+                    writeln!(f, "{}", section)?;
+                }
+                Ok(())
+            }
+            None => write!(f, "not supported"),
+        }
+    }
+}
+
+impl Data for Epc {
+    type Type = EpcSections;
+
+    #[cfg(target_arch = "x86_64")]
+    fn data(&self) -> Option<Self::Type> {
+        // CPUID leaf 0x7, sub-leaf 0, EBX bit 2 reports SGX support; the
+        // EPC section sub-leaves of leaf 0x12 are only meaningful if SGX
+        // is present at all.
+        let leaf7 = unsafe { __cpuid_count(0x7, 0) };
+        if leaf7.ebx & (1 << 2) == 0 {
+            return None;
+        }
+
+        Some(EpcSections::new())
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    fn data(&self) -> Option<Self::Type> {
+        None
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright 2020 Red Hat, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The enclave-side half of the SGX2 dynamic paging flow (EACCEPT /
+//! EACCEPTCOPY), built on top of `SecInfo`.
+//!
+//! Every host-side dynamic-paging leaf (EAUG, EMODPR, EMODT) must be
+//! followed by an enclave-side EACCEPT whose `SecInfo` describes the
+//! exact post-operation state of the page. `Eaccept` only exposes the
+//! constructors below, so callers cannot pass EACCEPT a flag/class
+//! combination that does not correspond to one of those operations.
+
+use super::{Flags, ModtTarget, SecInfo};
+
+/// The `SecInfo` an enclave must present to EACCEPT or EACCEPTCOPY.
+#[derive(Copy, Clone, Debug)]
+pub struct Eaccept(SecInfo);
+
+impl Eaccept {
+    /// Accepts a page the host has just augmented (EAUG).
+    pub fn aug(flags: Flags) -> Self {
+        Self(SecInfo::aug(flags))
+    }
+
+    /// Accepts a page whose permissions the host has just restricted
+    /// (EMODPR).
+    pub fn modpr(flags: Flags) -> Self {
+        Self(SecInfo::modpr(flags))
+    }
+
+    /// Accepts a page whose type the host has just changed (EMODT) to
+    /// `target`.
+    pub const fn modt(target: ModtTarget) -> Self {
+        Self(SecInfo::modt(target))
+    }
+
+    /// Accepts a page the host has just trimmed (EMODT to `Class::Trim`).
+    pub const fn trim() -> Self {
+        Self(SecInfo::trim())
+    }
+
+    /// The `SecInfo` to pass to EACCEPT/EACCEPTCOPY.
+    pub const fn secinfo(&self) -> SecInfo {
+        self.0
+    }
+}
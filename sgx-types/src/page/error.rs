@@ -0,0 +1,57 @@
+// Copyright 2020 Red Hat, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validation of SGX leaf functions (ENCLS/ENCLU) against page state.
+
+use super::{Class, Flags};
+use core::fmt;
+
+/// An SGX leaf function that operates on (or produces) an enclave page.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PageOp {
+    /// Adds a page to an enclave under construction.
+    Eadd,
+    /// Augments a running enclave with a new page.
+    Eaug,
+    /// Restricts a page's permissions.
+    Emodpr,
+    /// Changes a page's type.
+    Emodt,
+    /// Accepts a page, from inside the enclave.
+    Eaccept,
+    /// Removes a page from an enclave.
+    Eremove,
+}
+
+/// An attempt to perform a [`PageOp`] that the hardware would fault on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The page's `Class` does not permit this operation.
+    IllegalOperation(PageOp, Class),
+    /// The page's `Flags` are not legal for its `Class`.
+    IllegalFlags(Flags, Class),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IllegalOperation(op, class) => {
+                write!(f, "{:?} is not a legal operation on a {:?} page", op, class)
+            }
+            Self::IllegalFlags(flags, class) => {
+                write!(f, "{:?} are not legal flags for a {:?} page", flags, class)
+            }
+        }
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright 2020 Red Hat, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Page SecInfo (Section 38.11)
+//! These structs specify metadata about en enclave page.
+
+mod enclu;
+mod error;
+mod va;
+
+pub use enclu::Eaccept;
+pub use error::{Error, PageOp};
+pub use va::{VaPages, VaSlot, VA_SLOT_COUNT};
+
+use crate::prot::Prot;
+
+use bitflags::bitflags;
+use testaso::testaso;
+
+bitflags! {
+    /// The `Flags` of a page
+    ///
+    /// Section 38.11.1
+    ///
+    /// `#[repr(transparent)]` makes this a single-field newtype over
+    /// `u8` with a guaranteed C-compatible layout, so `SecInfo` (which
+    /// embeds it) stays FFI-safe across the loader's `extern "C"`
+    /// boundary.
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Flags: u8 {
+        /// The page can be read from inside the enclave.
+        const R = 1 << 0;
+
+        /// The page can be written from inside the enclave.
+        const W = 1 << 1;
+
+        /// The page can be executed from inside the enclave.
+        const X = 1 << 2;
+
+        /// The page is in the PENDING state.
+        const PENDING = 1 << 3;
+
+        /// The page is in the MODIFIED state.
+        const MODIFIED = 1 << 4;
+
+        /// A permission restriction operation on the page is in progress.
+        const PR = 1 << 5;
+    }
+}
+
+/// The `Class` of a page
+///
+/// The `Class` type is the `PAGE_TYPE` data structure, merely renamed
+/// due to the collision with the Rust `type` keyword.
+///
+/// Section 38.11.2
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Class {
+    /// Page is an SECS.
+    Secs = 0,
+    /// Page is a TCS.
+    Tcs = 1,
+    /// Page is a regular page.
+    Reg = 2,
+    /// Page is a Version Array.
+    Va = 3,
+    /// Page is in trimmed state.
+    Trim = 4,
+}
+
+impl Class {
+    /// Whether `op` is a legal SGX leaf function for a page of this
+    /// `Class`. Attempting an illegal combination faults the hardware
+    /// instead of returning an error, so this should be checked before
+    /// issuing the instruction.
+    pub const fn permits(self, op: PageOp) -> bool {
+        matches!(
+            (self, op),
+            (Self::Reg, PageOp::Eadd)
+                | (Self::Tcs, PageOp::Eadd)
+                | (Self::Reg, PageOp::Eaug)
+                | (Self::Reg, PageOp::Emodpr)
+                | (Self::Tcs, PageOp::Emodt)
+                | (Self::Reg, PageOp::Emodt)
+                | (Self::Reg, PageOp::Eaccept)
+                | (Self::Tcs, PageOp::Eaccept)
+                | (Self::Trim, PageOp::Eaccept)
+                | (Self::Trim, PageOp::Eremove)
+        )
+    }
+}
+
+/// The `Class` a page may be changed to via EMODT.
+///
+/// EMODT only ever targets `Class::Tcs` or `Class::Reg` (changing to
+/// `Class::Trim` goes through [`SecInfo::trim()`] instead, whose
+/// accepted flags differ), so this excludes every other `Class` a
+/// caller might otherwise have passed to [`SecInfo::modt()`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModtTarget {
+    /// Change the page to a TCS.
+    Tcs,
+    /// Change the page to a regular page.
+    Reg,
+}
+
+impl ModtTarget {
+    const fn class(self) -> Class {
+        match self {
+            Self::Tcs => Class::Tcs,
+            Self::Reg => Class::Reg,
+        }
+    }
+}
+
+/// The security information (`SecInfo`) about a page
+///
+/// Note that the `FLAGS` field from the SGX documentation is here
+/// divided into two fields (`flags` and `class`) for easy manipulation.
+///
+/// Section 38.11
+#[derive(Copy, Clone, Debug)]
+#[repr(C, align(64))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SecInfo {
+    /// Section 38.11.1
+    pub flags: Flags,
+    /// Section 38.11.2
+    pub class: Class,
+    reserved: [u16; 31],
+}
+
+impl AsRef<[u8]> for SecInfo {
+    fn as_ref(&self) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts(
+                self as *const Self as *const u8,
+                core::mem::size_of_val(self),
+            )
+        }
+    }
+}
+
+impl SecInfo {
+    /// Creates a SecInfo (page) of class type Regular.
+    pub const fn reg(flags: Flags) -> Self {
+        Self {
+            flags,
+            class: Class::Reg,
+            reserved: [0; 31],
+        }
+    }
+
+    /// Creates a SecInfo (page) of class type TCS.
+    pub const fn tcs() -> Self {
+        Self {
+            flags: Flags::empty(),
+            class: Class::Tcs,
+            reserved: [0; 31],
+        }
+    }
+
+    /// Creates the `SecInfo` an enclave must present to EACCEPT after the
+    /// host has augmented (EAUG) a new page.
+    ///
+    /// A freshly augmented page is always `Class::Reg` and is left in the
+    /// `PENDING` state until accepted, so `flags` should describe the
+    /// permissions the page is meant to have once accepted (e.g. `R | W`).
+    pub fn aug(flags: Flags) -> Self {
+        Self {
+            flags: (flags & (Flags::R | Flags::W | Flags::X)) | Flags::PENDING,
+            class: Class::Reg,
+            reserved: [0; 31],
+        }
+    }
+
+    /// Creates the `SecInfo` an enclave must present to EACCEPT after the
+    /// host has trimmed (EMODT to `Class::Trim`) a page.
+    pub const fn trim() -> Self {
+        Self {
+            flags: Flags::MODIFIED,
+            class: Class::Trim,
+            reserved: [0; 31],
+        }
+    }
+
+    /// Creates the `SecInfo` an enclave must present to EACCEPT after the
+    /// host has changed a page's type (EMODT) to `target`.
+    ///
+    /// `target` is restricted to [`ModtTarget`] so only the `Class::Tcs`
+    /// and `Class::Reg` targets of EMODT are reachable here; use
+    /// [`SecInfo::trim()`] for the `Class::Trim` target, whose accepted
+    /// flags differ.
+    pub const fn modt(target: ModtTarget) -> Self {
+        Self {
+            flags: Flags::MODIFIED,
+            class: target.class(),
+            reserved: [0; 31],
+        }
+    }
+
+    /// Creates the `SecInfo` an enclave must present to EACCEPT after the
+    /// host has restricted a page's permissions (EMODPR).
+    ///
+    /// `flags` are the permissions that remain after the restriction; the
+    /// `PR` bit is set automatically to reflect the in-progress
+    /// restriction, as required by EACCEPT.
+    pub fn modpr(flags: Flags) -> Self {
+        Self {
+            flags: (flags & (Flags::R | Flags::W | Flags::X)) | Flags::PR,
+            class: Class::Reg,
+            reserved: [0; 31],
+        }
+    }
+
+    /// Checks that `op` may legally be performed on a page with this
+    /// `SecInfo`, rejecting illegal class/flag combinations (such as `W`
+    /// on a `Class::Tcs` page, or execute permission on a `Class::Va`
+    /// page) before the caller issues the instruction and faults.
+    pub fn validate_for(&self, op: PageOp) -> Result<(), Error> {
+        if !self.class.permits(op) {
+            return Err(Error::IllegalOperation(op, self.class));
+        }
+
+        let illegal = match self.class {
+            Class::Tcs | Class::Va | Class::Secs => Flags::R | Flags::W | Flags::X,
+            Class::Reg | Class::Trim => Flags::empty(),
+        };
+
+        if self.flags.intersects(illegal) {
+            return Err(Error::IllegalFlags(self.flags, self.class));
+        }
+
+        Ok(())
+    }
+
+    /// The host VM protection implied by this page.
+    ///
+    /// This overrides the `R`/`W`/`X` flags by `Class`: a `Class::Tcs`
+    /// page is always mapped read/write regardless of its (empty)
+    /// flags, and `Class::Va`/`Class::Secs` pages get no user-accessible
+    /// protection. Deriving `prot` from `SecInfo` instead of passing it
+    /// separately keeps the loader's mapping in sync with the page's
+    /// actual permissions.
+    pub fn prot(&self) -> Prot {
+        match self.class {
+            Class::Tcs => Prot::READ | Prot::WRITE,
+            Class::Va | Class::Secs => Prot::empty(),
+            Class::Reg | Class::Trim => {
+                let mut prot = Prot::empty();
+                prot.set(Prot::READ, self.flags.contains(Flags::R));
+                prot.set(Prot::WRITE, self.flags.contains(Flags::W));
+                prot.set(Prot::EXEC, self.flags.contains(Flags::X));
+                prot
+            }
+        }
+    }
+}
+
+testaso! {
+    struct SecInfo: 64, 64 => {
+        flags: 0,
+        class: 1
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secinfo_serde_round_trip() {
+        let info = SecInfo::reg(Flags::R | Flags::W);
+
+        let json = serde_json::to_string(&info).unwrap();
+        let back: SecInfo = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(back.flags, info.flags);
+        assert_eq!(back.class, info.class);
+    }
+}
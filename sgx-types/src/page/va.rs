@@ -0,0 +1,152 @@
+// Copyright 2020 Red Hat, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Version Array (VA) page tracking (Section 38.11.2).
+//!
+//! A VA page backs up to [`VA_SLOT_COUNT`] EPC pages: EWB writes an
+//! encrypted page out to regular memory and consumes a free slot, and
+//! ELDU reloads the page using that same slot. `VaPages` mirrors the
+//! kernel's grow/shrink accounting, allocating a new `Class::Va` page
+//! every `VA_SLOT_COUNT` tracked pages and freeing the last one once it
+//! is empty again.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use super::{Class, Flags, SecInfo};
+
+/// The number of slots in a single Version Array page.
+///
+/// Section 38.11.2.
+pub const VA_SLOT_COUNT: u16 = 512;
+
+/// A reserved slot within a `Class::Va` page.
+///
+/// Returned by [`VaPages::grow()`] and [`VaPages::shrink()`]; the same
+/// `(page, slot)` pair must be presented to EWB and the matching ELDU.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VaSlot {
+    page: usize,
+    slot: u16,
+}
+
+impl VaSlot {
+    /// The index of the VA page owning this slot, in allocation order.
+    pub const fn page(&self) -> usize {
+        self.page
+    }
+
+    /// The index of this slot within its VA page.
+    pub const fn slot(&self) -> u16 {
+        self.slot
+    }
+}
+
+/// A single `Class::Va` page and its slot accounting.
+#[derive(Copy, Clone, Debug, Default)]
+struct VaPage {
+    occupied: u16,
+}
+
+impl VaPage {
+    const fn is_full(&self) -> bool {
+        self.occupied >= VA_SLOT_COUNT
+    }
+
+    /// The number of slots still free in this page.
+    const fn free(&self) -> u16 {
+        VA_SLOT_COUNT - self.occupied
+    }
+
+    /// The number of slots already handed out by this page.
+    const fn occupied(&self) -> u16 {
+        self.occupied
+    }
+}
+
+/// Tracks the `Class::Va` pages backing an enclave's EPC pages.
+#[derive(Debug, Default)]
+pub struct VaPages {
+    pages: Vec<VaPage>,
+}
+
+impl VaPages {
+    /// Creates an empty VA page tracker.
+    pub const fn new() -> Self {
+        Self { pages: Vec::new() }
+    }
+
+    /// The number of `Class::Va` pages currently allocated.
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Whether no `Class::Va` pages are currently allocated.
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+
+    /// Reserves a slot for one additional EPC page, allocating a new
+    /// `Class::Va` page first if the current one is full.
+    pub fn grow(&mut self) -> VaSlot {
+        if self.pages.last().is_none_or(VaPage::is_full) {
+            self.pages.push(VaPage::default());
+        }
+
+        let page = self.pages.len() - 1;
+        let occupied = &mut self.pages[page].occupied;
+        let slot = *occupied;
+        *occupied += 1;
+
+        VaSlot { page, slot }
+    }
+
+    /// Releases the most recently reserved slot, freeing its VA page if
+    /// that was its last occupied slot.
+    ///
+    /// Returns `None` if no slots are currently reserved.
+    pub fn shrink(&mut self) -> Option<VaSlot> {
+        let page = self.pages.len().checked_sub(1)?;
+        let occupied = &mut self.pages[page].occupied;
+        *occupied -= 1;
+        let slot = *occupied;
+
+        if slot == 0 {
+            self.pages.pop();
+        }
+
+        Some(VaSlot { page, slot })
+    }
+
+    /// The number of free slots in the most recently allocated VA page.
+    pub fn free(&self) -> u16 {
+        self.pages.last().map_or(0, VaPage::free)
+    }
+
+    /// The number of occupied slots in the most recently allocated VA
+    /// page.
+    pub fn occupied(&self) -> u16 {
+        self.pages.last().map_or(0, VaPage::occupied)
+    }
+
+    /// The `SecInfo` describing a newly allocated VA page.
+    pub const fn secinfo() -> SecInfo {
+        SecInfo {
+            flags: Flags::empty(),
+            class: Class::Va,
+            reserved: [0; 31],
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2020 Red Hat, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Host VM protection bits for mapping enclave pages.
+
+use bitflags::bitflags;
+
+bitflags! {
+    /// The host mmap protection of an enclave page.
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Prot: u8 {
+        /// The page can be read by the host.
+        const READ = 1 << 0;
+
+        /// The page can be written by the host.
+        const WRITE = 1 << 1;
+
+        /// The page can be executed by the host.
+        const EXEC = 1 << 2;
+    }
+}